@@ -0,0 +1,103 @@
+use alloy_primitives::Uint;
+
+/// Accumulates voting power from distinct signers and checks it against a quorum threshold.
+///
+/// Power is summed with overflow-safe `checked_add`, which avoids the silent wraparound a plain
+/// `Uint` addition would otherwise hide when tallying a large validator set. The quorum
+/// threshold is expressed as a `numerator / denominator` fraction so the same prover can support
+/// quorum fractions other than classic BFT 2/3+. The comparison is always strict: power exactly
+/// equal to the fraction of the total is not a quorum, so `with_threshold(2, 3)` is the same rule
+/// as [`VotingPowerAccumulator::new`].
+pub struct VotingPowerAccumulator {
+    accumulated: Uint<256, 4>,
+    numerator: Uint<256, 4>,
+    denominator: Uint<256, 4>,
+}
+
+impl VotingPowerAccumulator {
+    /// Creates an accumulator using the classic BFT 2/3+ rule: `accumulated * 3 > total * 2`.
+    pub fn new() -> Self {
+        Self::with_threshold(2, 3)
+    }
+
+    /// Creates an accumulator with a configurable `numerator / denominator` quorum threshold.
+    pub fn with_threshold(numerator: u64, denominator: u64) -> Self {
+        VotingPowerAccumulator {
+            accumulated: Uint::from(0),
+            numerator: Uint::from(numerator),
+            denominator: Uint::from(denominator),
+        }
+    }
+
+    /// Adds a signer's power to the running tally.
+    ///
+    /// Panics on overflow rather than wrapping silently, since a wrapped tally could otherwise
+    /// be made to look like it met quorum when it didn't.
+    pub fn add_power(&mut self, power: Uint<256, 4>) {
+        self.accumulated = self
+            .accumulated
+            .checked_add(power)
+            .expect("voting power accumulator overflowed");
+    }
+
+    /// Returns the running sum of power added so far.
+    pub fn accumulated(&self) -> Uint<256, 4> {
+        self.accumulated
+    }
+
+    /// Returns whether the accumulated power meets the configured quorum threshold out of
+    /// `total_power`, i.e. `accumulated * denominator > total_power * numerator`.
+    ///
+    /// Both sides are computed with a widening multiplication rather than `checked_mul` so that
+    /// a legitimate `accumulated` or `total_power` near `Uint::MAX` doesn't need headroom past
+    /// `Uint::MAX` to be compared — only actual `Uint::MAX`-sized *products* would, and this
+    /// never materializes a product narrower than it needs to be.
+    pub fn has_quorum(&self, total_power: Uint<256, 4>) -> bool {
+        let lhs: Uint<512, 8> = self.accumulated.widening_mul(self.denominator);
+        let rhs: Uint<512, 8> = total_power.widening_mul(self.numerator);
+        lhs > rhs
+    }
+}
+
+impl Default for VotingPowerAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exactly_two_thirds_is_not_quorum() {
+        let mut acc = VotingPowerAccumulator::new();
+        acc.add_power(Uint::from(200));
+        assert!(!acc.has_quorum(Uint::from(300)));
+    }
+
+    #[test]
+    fn just_over_two_thirds_is_quorum() {
+        let mut acc = VotingPowerAccumulator::new();
+        acc.add_power(Uint::from(201));
+        assert!(acc.has_quorum(Uint::from(300)));
+    }
+
+    #[test]
+    fn accumulation_near_u256_max_does_not_panic_or_wrap() {
+        let mut acc = VotingPowerAccumulator::new();
+        let half_max = Uint::<256, 4>::MAX / Uint::from(2);
+        acc.add_power(half_max);
+        acc.add_power(half_max);
+        assert!(acc.accumulated() <= Uint::<256, 4>::MAX);
+        assert!(acc.has_quorum(Uint::<256, 4>::MAX / Uint::from(2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflowed")]
+    fn accumulation_past_u256_max_panics() {
+        let mut acc = VotingPowerAccumulator::new();
+        acc.add_power(Uint::<256, 4>::MAX);
+        acc.add_power(Uint::from(1));
+    }
+}
@@ -0,0 +1,161 @@
+use crate::common::{self, PublicValuesStruct};
+use crate::helper::*;
+use std::collections::HashMap;
+
+use bincode;
+
+use alloy_primitives::{Address, FixedBytes};
+use reth_primitives::{keccak256, Header};
+use sp1_cc_client_executor::{io::EVMStateSketch, ClientExecutor};
+
+#[derive(Clone)]
+pub struct CheckpointProofInputs {
+    pub tx_data: String,
+    pub tx_hash: FixedBytes<32>,
+    /// The expected CometBFT chain-id precommits must be signed for, e.g. `heimdall-137`.
+    pub chain_id: String,
+    pub precommits: Vec<Vec<u8>>,
+    pub sigs: Vec<String>,
+    pub signers: Vec<Address>,
+    /// The Bor headers for every block in `[start_block, end_block]`, in order.
+    pub bor_headers: Vec<Header>,
+    pub bor_block_hash: FixedBytes<32>,
+    pub state_sketch_bytes: Vec<u8>,
+    pub l1_block_hash: FixedBytes<32>,
+}
+
+pub struct CheckpointProver {
+    inputs: CheckpointProofInputs,
+}
+
+impl CheckpointProver {
+    pub fn init(inputs: CheckpointProofInputs) -> Self {
+        CheckpointProver { inputs }
+    }
+
+    pub fn prove(&self) -> PublicValuesStruct {
+        // Verify if the transaction data provided is actually correct or not.
+        let checkpoint = verify_checkpoint_tx_data(&self.inputs.tx_data, &self.inputs.tx_hash);
+
+        // Verify that the submitted Bor headers are exactly the contiguous range the checkpoint
+        // claims to cover, and that they hash to the checkpoint's root.
+        assert_eq!(
+            self.inputs.bor_headers.len() as u64,
+            checkpoint.end_block - checkpoint.start_block + 1,
+            "bor headers do not cover the full checkpoint block range"
+        );
+        for (i, header) in self.inputs.bor_headers.iter().enumerate() {
+            assert_eq!(
+                header.number,
+                checkpoint.start_block + i as u64,
+                "bor headers are not a contiguous [start_block, end_block] range"
+            );
+        }
+        assert_eq!(
+            checkpoint_root(&self.inputs.bor_headers),
+            checkpoint.root_hash,
+            "bor headers do not hash to the checkpoint's root hash"
+        );
+
+        // Make sure that we have equal number of precommits, signatures and signers.
+        assert_eq!(self.inputs.precommits.len(), self.inputs.sigs.len());
+        assert_eq!(self.inputs.sigs.len(), self.inputs.signers.len());
+
+        let state_sketch =
+            bincode::deserialize::<EVMStateSketch>(&self.inputs.state_sketch_bytes).unwrap();
+
+        // Initialize the client executor with the state sketch.
+        // This step also validates all of the storage against the provided state root.
+        let executor = ClientExecutor::new(state_sketch).unwrap();
+
+        // Fetch the active validator's info from L1.
+        let (signers, powers, total_power) = common::fetch_validator_info(&executor);
+        let mut validator_stakes = HashMap::new();
+        for (i, signer) in signers.iter().enumerate() {
+            validator_stakes.insert(*signer, powers[i]);
+        }
+
+        // Verify the precommits, rejecting equivocation and disagreement on height/round, and
+        // tally the voting power behind them.
+        let majority_power = common::verify_quorum(
+            &self.inputs.precommits,
+            &self.inputs.sigs,
+            &self.inputs.signers,
+            &validator_stakes,
+            &self.inputs.tx_hash,
+            &self.inputs.chain_id,
+        );
+
+        // Check if the majority power meets the 2/3rd+ quorum threshold.
+        if !majority_power.has_quorum(total_power) {
+            panic!("Majority voting power does not exceed 2/3rd of the total power");
+        }
+
+        PublicValuesStruct {
+            bor_block_hash: self.inputs.bor_block_hash,
+            l1_block_hash: self.inputs.l1_block_hash,
+        }
+    }
+}
+
+/// Computes the checkpoint's Merkle root over its Bor headers: the leaves are each header's
+/// block hash, folded pairwise with `keccak256`, duplicating the last leaf when a level has an
+/// odd number of nodes.
+///
+/// This must match whatever algorithm produced `checkpoint.root_hash` on L1, so it cannot be
+/// changed to a different tree construction (e.g. RFC 6962's domain-separated, power-of-two-split
+/// variant) without bit-for-bit verification against real checkpoint data — a different
+/// construction commits to a different root for the same leaves.
+fn checkpoint_root(headers: &[Header]) -> FixedBytes<32> {
+    let mut level: Vec<FixedBytes<32>> =
+        headers.iter().map(|header| header.hash_slow()).collect();
+    assert!(!level.is_empty(), "checkpoint must cover at least one block");
+
+    while level.len() > 1 {
+        if level.len() % 2 == 1 {
+            level.push(*level.last().unwrap());
+        }
+        level = level
+            .chunks(2)
+            .map(|pair| keccak256([pair[0].as_slice(), pair[1].as_slice()].concat()))
+            .collect();
+    }
+
+    level[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header_with_number(number: u64) -> Header {
+        Header {
+            number,
+            ..Default::default()
+        }
+    }
+
+    fn headers(numbers: impl IntoIterator<Item = u64>) -> Vec<Header> {
+        numbers.into_iter().map(header_with_number).collect()
+    }
+
+    #[test]
+    fn single_header_root_is_its_hash() {
+        let header = header_with_number(1);
+        assert_eq!(checkpoint_root(&[header.clone()]), header.hash_slow());
+    }
+
+    #[test]
+    fn root_is_order_sensitive() {
+        let forward = checkpoint_root(&headers(1..=4));
+        let reversed = checkpoint_root(&headers((1..=4).rev()));
+        assert_ne!(forward, reversed);
+    }
+
+    #[test]
+    fn different_block_counts_yield_different_roots() {
+        let three = checkpoint_root(&headers(1..=3));
+        let four = checkpoint_root(&headers(1..=4));
+        assert_ne!(three, four);
+    }
+}
@@ -0,0 +1,158 @@
+//! Logic shared between [`crate::milestone::MilestoneProver`] and
+//! [`crate::checkpoint::CheckpointProver`]: the on-chain validator-set fetch, canonical precommit
+//! verification, and the 2/3+ quorum tally. Both provers emit the same [`PublicValuesStruct`] so
+//! `ConsensusProofVerifier` can validate either proof type.
+
+use std::collections::{HashMap, HashSet};
+
+use alloy_primitives::{address, Address, FixedBytes, Uint};
+use alloy_sol_types::{sol, SolCall};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use reth_primitives::keccak256;
+use sp1_cc_client_executor::{ClientExecutor, ContractInput};
+
+use crate::accumulator::VotingPowerAccumulator;
+use crate::helper::{verify_precommit, verify_signature};
+
+sol! {
+    /// The public values encoded as a struct that can be easily deserialized inside Solidity.
+    struct PublicValuesStruct {
+        bytes32 bor_block_hash;
+        bytes32 l1_block_hash;
+    }
+}
+
+sol! {
+    contract ConsensusProofVerifier {
+        function verifyConsensusProof(bytes calldata _proofBytes, bytes32 bor_block_hash, bytes32 l1_block_hash) public view;
+        function getEncodedValidatorInfo() public view returns(address[] memory, uint256[] memory, uint256);
+    }
+}
+
+pub const VERIFIER_CONTRACT: Address = address!("1d42064Fc4Beb5F8aAF85F4617AE8b3b5B8Bd801");
+pub const CALLER: Address = address!("0000000000000000000000000000000000000000");
+
+/// Fetches the active validator set (signers, powers, total power) from L1 via the client
+/// executor's state sketch.
+pub fn fetch_validator_info(executor: &ClientExecutor) -> (Vec<Address>, Vec<Uint<256, 4>>, Uint<256, 4>) {
+    let call = ConsensusProofVerifier::getEncodedValidatorInfoCall {};
+    let input = ContractInput {
+        contract_address: VERIFIER_CONTRACT,
+        caller_address: CALLER,
+        calldata: call.clone(),
+    };
+    let output = executor.execute(input).unwrap();
+    let response = ConsensusProofVerifier::getEncodedValidatorInfoCall::abi_decode_returns(
+        &output.contractOutput,
+        true,
+    )
+    .unwrap();
+
+    (response._0, response._1, response._2)
+}
+
+/// The outcome of independently verifying a single precommit: who signed it and the
+/// height/round it was cast at. Collected per-precommit (optionally in parallel) and then
+/// folded sequentially into the quorum tally.
+struct VerifiedPrecommit {
+    signer: Address,
+    height: i64,
+    round: i64,
+}
+
+fn verify_one_precommit(
+    precommits: &[Vec<u8>],
+    sigs: &[String],
+    signers: &[Address],
+    validator_stakes: &HashMap<Address, Uint<256, 4>>,
+    tx_hash: &FixedBytes<32>,
+    chain_id: &str,
+    i: usize,
+) -> VerifiedPrecommit {
+    let signer = signers[i];
+
+    // Validate if the signer of this precommit message is a part of the active validator set.
+    assert!(validator_stakes.contains_key(&signer));
+
+    // Verify the precommit is a canonical vote for this transaction, on the expected chain, and
+    // recover the (height, round) it was cast for.
+    let precommit = &precommits[i];
+    let (height, round) = verify_precommit(precommit, tx_hash, chain_id);
+
+    // Verify if the message is indeed signed by the validator or not.
+    verify_signature(sigs[i].as_str(), &keccak256(precommit), signer);
+
+    VerifiedPrecommit {
+        signer,
+        height,
+        round,
+    }
+}
+
+/// Verifies every precommit for `tx_hash` against the active `validator_stakes`, rejecting a
+/// validator whose stake would otherwise be counted more than once (whether by repeating an
+/// identical precommit or by equivocating with a second, different one) and any disagreement on
+/// height/round across the set. Returns the accumulated voting power so the caller can check it
+/// against quorum.
+///
+/// `verify_precommit` (see `helper.rs`) already forces every surviving precommit's vote to be
+/// for `tx_hash`, so there is only one block hash any signer here could possibly have voted for
+/// — a validator that double-signs two *conflicting* precommits is rejected earlier, inside
+/// `verify_precommit` itself, with "precommit does not vote for the expected block hash". What's
+/// left to reject here is a validator supplying the *same* vote twice to inflate its counted
+/// power, which `counted_signers` below catches.
+///
+/// Precommit parsing, signer-membership, and signature recovery run in parallel when the
+/// `rayon` feature is enabled (host-side proving); the zkVM guest build keeps the sequential
+/// path. Either way the results are folded into the tally sequentially so it stays deterministic.
+pub fn verify_quorum(
+    precommits: &[Vec<u8>],
+    sigs: &[String],
+    signers: &[Address],
+    validator_stakes: &HashMap<Address, Uint<256, 4>>,
+    tx_hash: &FixedBytes<32>,
+    chain_id: &str,
+) -> VotingPowerAccumulator {
+    assert_eq!(precommits.len(), sigs.len());
+    assert_eq!(sigs.len(), signers.len());
+
+    let indices = 0..precommits.len();
+    #[cfg(feature = "rayon")]
+    let verified: Vec<VerifiedPrecommit> = indices
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|i| verify_one_precommit(precommits, sigs, signers, validator_stakes, tx_hash, chain_id, i))
+        .collect();
+    #[cfg(not(feature = "rayon"))]
+    let verified: Vec<VerifiedPrecommit> = indices
+        .map(|i| verify_one_precommit(precommits, sigs, signers, validator_stakes, tx_hash, chain_id, i))
+        .collect();
+
+    let mut counted_signers: HashSet<Address> = HashSet::new();
+    let mut expected_round: Option<(i64, i64)> = None;
+    let mut majority_power = VotingPowerAccumulator::new();
+
+    for v in verified {
+        match expected_round {
+            Some(expected) => assert_eq!(
+                (v.height, v.round),
+                expected,
+                "precommit from {} does not share the height/round of the rest of the proof",
+                v.signer
+            ),
+            None => expected_round = Some((v.height, v.round)),
+        }
+
+        // A validator must not be counted twice toward the majority power.
+        assert!(
+            counted_signers.insert(v.signer),
+            "validator {} appears more than once in the precommit set",
+            v.signer
+        );
+
+        majority_power.add_power(validator_stakes[&v.signer]);
+    }
+
+    majority_power
+}
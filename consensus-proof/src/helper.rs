@@ -0,0 +1,381 @@
+use alloy_primitives::{Address, FixedBytes};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use reth_primitives::keccak256;
+
+/// A decoded Heimdall milestone transaction, as returned by [`verify_tx_data`].
+pub struct Milestone {
+    pub end_block: u64,
+    pub hash: Vec<u8>,
+}
+
+/// A decoded Heimdall checkpoint transaction, as returned by [`verify_checkpoint_tx_data`].
+pub struct Checkpoint {
+    pub start_block: u64,
+    pub end_block: u64,
+    pub root_hash: FixedBytes<32>,
+}
+
+/// A CometBFT/Tendermint canonical vote, decoded from the sign-bytes a validator actually signs
+/// (see [`verify_precommit`]).
+pub struct CanonicalVote {
+    pub height: i64,
+    pub round: i64,
+    pub block_hash: FixedBytes<32>,
+    pub chain_id: String,
+}
+
+/// Decodes and hash-checks a base64-encoded Heimdall milestone transaction.
+pub fn verify_tx_data(tx_data: &str, tx_hash: &FixedBytes<32>) -> Milestone {
+    let decoded = STANDARD
+        .decode(tx_data)
+        .expect("tx_data is not valid base64");
+    assert_eq!(
+        keccak256(&decoded),
+        *tx_hash,
+        "tx_data does not hash to the given tx_hash"
+    );
+
+    let mut pos = 0;
+    let mut end_block = 0u64;
+    let mut hash = Vec::new();
+    while pos < decoded.len() {
+        let tag = read_varint(&decoded, &mut pos);
+        match (tag >> 3, tag & 0x7) {
+            (3, 0) => end_block = read_varint(&decoded, &mut pos),
+            (4, 2) => {
+                let len = read_varint(&decoded, &mut pos) as usize;
+                hash = decoded[pos..pos + len].to_vec();
+                pos += len;
+            }
+            (_, 0) => {
+                read_varint(&decoded, &mut pos);
+            }
+            (_, 1) => pos += 8,
+            (_, 2) => {
+                let len = read_varint(&decoded, &mut pos) as usize;
+                pos += len;
+            }
+            (_, 5) => pos += 4,
+            _ => panic!("unsupported wire type in milestone tx_data encoding"),
+        }
+    }
+
+    Milestone { end_block, hash }
+}
+
+/// Decodes and hash-checks a base64-encoded Heimdall checkpoint transaction.
+pub fn verify_checkpoint_tx_data(tx_data: &str, tx_hash: &FixedBytes<32>) -> Checkpoint {
+    let decoded = STANDARD
+        .decode(tx_data)
+        .expect("tx_data is not valid base64");
+    assert_eq!(
+        keccak256(&decoded),
+        *tx_hash,
+        "tx_data does not hash to the given tx_hash"
+    );
+
+    let mut pos = 0;
+    let mut start_block = 0u64;
+    let mut end_block = 0u64;
+    let mut root_hash = FixedBytes::<32>::ZERO;
+    while pos < decoded.len() {
+        let tag = read_varint(&decoded, &mut pos);
+        match (tag >> 3, tag & 0x7) {
+            (2, 0) => start_block = read_varint(&decoded, &mut pos),
+            (3, 0) => end_block = read_varint(&decoded, &mut pos),
+            (4, 2) => {
+                let len = read_varint(&decoded, &mut pos) as usize;
+                root_hash = FixedBytes::from_slice(&decoded[pos..pos + len]);
+                pos += len;
+            }
+            (_, 0) => {
+                read_varint(&decoded, &mut pos);
+            }
+            (_, 1) => pos += 8,
+            (_, 2) => {
+                let len = read_varint(&decoded, &mut pos) as usize;
+                pos += len;
+            }
+            (_, 5) => pos += 4,
+            _ => panic!("unsupported wire type in checkpoint tx_data encoding"),
+        }
+    }
+
+    Checkpoint {
+        start_block,
+        end_block,
+        root_hash,
+    }
+}
+
+/// Verifies a validator's signature over `digest`.
+pub fn verify_signature(sig: &str, digest: &FixedBytes<32>, signer: Address) {
+    let sig_bytes = STANDARD.decode(sig).expect("signature is not valid base64");
+    let recovered = alloy_primitives::Signature::try_from(sig_bytes.as_slice())
+        .expect("malformed signature")
+        .recover_address_from_prehash(digest)
+        .expect("failed to recover signer from signature");
+    assert_eq!(recovered, signer, "signature was not produced by the claimed signer");
+}
+
+/// Parses a raw CometBFT precommit as a canonical vote and verifies it votes for `tx_hash` on
+/// `expected_chain_id`. Returns the vote's `(height, round)` so callers can enforce that every
+/// precommit in a proof agrees on the same height/round.
+///
+/// Precommits are signed over the length-delimited protobuf encoding of `CanonicalVote { Type,
+/// Height (sfixed64), Round (sfixed64), BlockID, Timestamp, ChainID }` with `Type == 2`
+/// (precommit). Parsing these sign-bytes directly (rather than mutating the raw precommit before
+/// hashing), while the caller checks the signature over the same bytes, binds the signature to
+/// the chain-id, height and round instead of trusting attacker-chosen bytes. The bytes are parsed
+/// as supplied and are not re-encoded: unknown fields are skipped, and the leading length prefix
+/// is not checked against the rest of the buffer.
+pub fn verify_precommit(precommit: &[u8], tx_hash: &FixedBytes<32>, expected_chain_id: &str) -> (i64, i64) {
+    let vote = parse_canonical_vote(precommit);
+    assert_eq!(
+        vote.block_hash, *tx_hash,
+        "precommit does not vote for the expected block hash"
+    );
+    assert_eq!(
+        vote.chain_id, expected_chain_id,
+        "precommit was signed for the wrong chain id"
+    );
+    (vote.height, vote.round)
+}
+
+fn parse_canonical_vote(bytes: &[u8]) -> CanonicalVote {
+    // The sign-bytes are a varint-length-prefixed protobuf encoding of CanonicalVote.
+    let mut pos = 0;
+    let _len = read_varint(bytes, &mut pos);
+
+    let mut vote_type = 0u64;
+    let mut height = 0i64;
+    let mut round = 0i64;
+    let mut block_hash = FixedBytes::<32>::ZERO;
+    let mut chain_id = String::new();
+
+    while pos < bytes.len() {
+        let tag = read_varint(bytes, &mut pos);
+        match (tag >> 3, tag & 0x7) {
+            (1, 0) => vote_type = read_varint(bytes, &mut pos),
+            (2, 1) => {
+                height = i64::from_le_bytes(bytes[pos..pos + 8].try_into().unwrap());
+                pos += 8;
+            }
+            (3, 1) => {
+                round = i64::from_le_bytes(bytes[pos..pos + 8].try_into().unwrap());
+                pos += 8;
+            }
+            (4, 2) => {
+                let len = read_varint(bytes, &mut pos) as usize;
+                block_hash = parse_block_id_hash(&bytes[pos..pos + len]);
+                pos += len;
+            }
+            (6, 2) => {
+                let len = read_varint(bytes, &mut pos) as usize;
+                chain_id = String::from_utf8(bytes[pos..pos + len].to_vec())
+                    .expect("chain id is not valid utf8");
+                pos += len;
+            }
+            (_, 0) => {
+                read_varint(bytes, &mut pos);
+            }
+            (_, 1) => pos += 8,
+            (_, 2) => {
+                let len = read_varint(bytes, &mut pos) as usize;
+                pos += len;
+            }
+            (_, 5) => pos += 4,
+            _ => panic!("unsupported wire type in canonical vote encoding"),
+        }
+    }
+
+    assert_eq!(vote_type, 2, "canonical vote is not a precommit (Type != 2)");
+    CanonicalVote {
+        height,
+        round,
+        block_hash,
+        chain_id,
+    }
+}
+
+/// Extracts `BlockID.hash` (field 1) from a nested `CanonicalBlockID` submessage.
+fn parse_block_id_hash(block_id: &[u8]) -> FixedBytes<32> {
+    let mut pos = 0;
+    while pos < block_id.len() {
+        let tag = read_varint(block_id, &mut pos);
+        let (field_number, wire_type) = (tag >> 3, tag & 0x7);
+        if field_number == 1 && wire_type == 2 {
+            let len = read_varint(block_id, &mut pos) as usize;
+            return FixedBytes::from_slice(&block_id[pos..pos + len]);
+        }
+        match wire_type {
+            0 => {
+                read_varint(block_id, &mut pos);
+            }
+            1 => pos += 8,
+            2 => {
+                let len = read_varint(block_id, &mut pos) as usize;
+                pos += len;
+            }
+            5 => pos += 4,
+            _ => panic!("unsupported wire type in BlockID encoding"),
+        }
+    }
+    panic!("CanonicalBlockID is missing its hash field");
+}
+
+fn read_varint(buf: &[u8], pos: &mut usize) -> u64 {
+    let mut result = 0u64;
+    let mut shift = 0;
+    loop {
+        let byte = buf[*pos];
+        *pos += 1;
+        result |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode_varint(mut value: u64, out: &mut Vec<u8>) {
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                out.push(byte);
+                break;
+            }
+            out.push(byte | 0x80);
+        }
+    }
+
+    /// Builds the length-delimited `CanonicalVote` sign-bytes `verify_precommit` expects:
+    /// `Type=2` (precommit), `Height`/`Round` as sfixed64, `BlockID{hash}`, and `ChainID`. An
+    /// unrelated varint field (field 7) is always included to exercise the "skip unknown field"
+    /// path every real vote also has to tolerate (e.g. an unrecognized/newer field).
+    fn encode_canonical_vote(height: i64, round: i64, block_hash: &[u8; 32], chain_id: &str) -> Vec<u8> {
+        let mut body = Vec::new();
+
+        body.push(0x08); // field 1 (Type), varint
+        encode_varint(2, &mut body);
+
+        if height != 0 {
+            body.push(0x11); // field 2 (Height), fixed64
+            body.extend_from_slice(&height.to_le_bytes());
+        }
+        if round != 0 {
+            body.push(0x19); // field 3 (Round), fixed64
+            body.extend_from_slice(&round.to_le_bytes());
+        }
+
+        let mut block_id = Vec::new();
+        block_id.push(0x0a); // BlockID field 1 (Hash), bytes
+        encode_varint(32, &mut block_id);
+        block_id.extend_from_slice(block_hash);
+        body.push(0x22); // field 4 (BlockID), length-delimited
+        encode_varint(block_id.len() as u64, &mut body);
+        body.extend_from_slice(&block_id);
+
+        body.push(0x38); // field 7, varint (unknown to the parser, must be skipped)
+        encode_varint(42, &mut body);
+
+        body.push(0x32); // field 6 (ChainID), length-delimited
+        encode_varint(chain_id.len() as u64, &mut body);
+        body.extend_from_slice(chain_id.as_bytes());
+
+        let mut precommit = Vec::new();
+        encode_varint(body.len() as u64, &mut precommit);
+        precommit.extend_from_slice(&body);
+        precommit
+    }
+
+    #[test]
+    fn read_varint_round_trips_single_and_multi_byte_values() {
+        for value in [0u64, 1, 127, 128, 300, u32::MAX as u64, u64::MAX] {
+            let mut buf = Vec::new();
+            encode_varint(value, &mut buf);
+            let mut pos = 0;
+            assert_eq!(read_varint(&buf, &mut pos), value);
+            assert_eq!(pos, buf.len());
+        }
+    }
+
+    #[test]
+    fn parse_canonical_vote_reads_all_fields_and_skips_unknown_ones() {
+        let block_hash = [0x11u8; 32];
+        let precommit = encode_canonical_vote(100, 1, &block_hash, "heimdall-137");
+
+        let vote = parse_canonical_vote(&precommit);
+        assert_eq!(vote.height, 100);
+        assert_eq!(vote.round, 1);
+        assert_eq!(vote.block_hash.as_slice(), &block_hash);
+        assert_eq!(vote.chain_id, "heimdall-137");
+    }
+
+    #[test]
+    fn parse_canonical_vote_defaults_omitted_zero_round_to_zero() {
+        // proto3 omits default-valued scalar fields on the wire, e.g. round 0.
+        let block_hash = [0x22u8; 32];
+        let precommit = encode_canonical_vote(50, 0, &block_hash, "heimdall-137");
+
+        let vote = parse_canonical_vote(&precommit);
+        assert_eq!(vote.round, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Type != 2")]
+    fn parse_canonical_vote_rejects_non_precommit_types() {
+        let mut body = Vec::new();
+        body.push(0x08); // field 1 (Type), varint
+        encode_varint(1, &mut body); // 1 = Prevote, not Precommit
+
+        let mut precommit = Vec::new();
+        encode_varint(body.len() as u64, &mut precommit);
+        precommit.extend_from_slice(&body);
+
+        parse_canonical_vote(&precommit);
+    }
+
+    #[test]
+    #[should_panic]
+    fn parse_canonical_vote_panics_on_truncated_input() {
+        let block_hash = [0x33u8; 32];
+        let mut precommit = encode_canonical_vote(10, 0, &block_hash, "heimdall-137");
+        precommit.truncate(precommit.len() - 10);
+
+        parse_canonical_vote(&precommit);
+    }
+
+    #[test]
+    fn verify_precommit_accepts_a_matching_vote() {
+        let block_hash = [0x44u8; 32];
+        let precommit = encode_canonical_vote(10, 0, &block_hash, "heimdall-137");
+
+        let (height, round) = verify_precommit(&precommit, &FixedBytes::from(block_hash), "heimdall-137");
+        assert_eq!((height, round), (10, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong chain id")]
+    fn verify_precommit_rejects_the_wrong_chain_id() {
+        let block_hash = [0x55u8; 32];
+        let precommit = encode_canonical_vote(10, 0, &block_hash, "heimdall-137");
+
+        verify_precommit(&precommit, &FixedBytes::from(block_hash), "heimdall-mainnet");
+    }
+
+    #[test]
+    #[should_panic(expected = "expected block hash")]
+    fn verify_precommit_rejects_a_mismatched_block_hash() {
+        let block_hash = [0x66u8; 32];
+        let precommit = encode_canonical_vote(10, 0, &block_hash, "heimdall-137");
+
+        verify_precommit(&precommit, &FixedBytes::from([0x77u8; 32]), "heimdall-137");
+    }
+}
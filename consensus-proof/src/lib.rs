@@ -0,0 +1,5 @@
+pub mod accumulator;
+pub mod checkpoint;
+pub mod common;
+pub mod helper;
+pub mod milestone;
@@ -1,35 +1,19 @@
+use crate::common::{self, PublicValuesStruct};
 use crate::helper::*;
 use std::collections::HashMap;
 
 use bincode;
 
-use alloy_primitives::{address, Address, FixedBytes, Uint};
-use alloy_sol_types::{sol, SolCall};
-use reth_primitives::{keccak256, Header};
-use sp1_cc_client_executor::{io::EVMStateSketch, ClientExecutor, ContractInput};
-
-sol! {
-    /// The public values encoded as a struct that can be easily deserialized inside Solidity.
-    struct PublicValuesStruct {
-        bytes32 bor_block_hash;
-        bytes32 l1_block_hash;
-    }
-}
-
-sol! {
-    contract ConsensusProofVerifier {
-        function verifyConsensusProof(bytes calldata _proofBytes, bytes32 bor_block_hash, bytes32 l1_block_hash) public view;
-        function getEncodedValidatorInfo() public view returns(address[] memory, uint256[] memory, uint256);
-    }
-}
-
-const VERIFIER_CONTRACT: Address = address!("1d42064Fc4Beb5F8aAF85F4617AE8b3b5B8Bd801");
-const CALLER: Address = address!("0000000000000000000000000000000000000000");
+use alloy_primitives::{Address, FixedBytes};
+use reth_primitives::Header;
+use sp1_cc_client_executor::{io::EVMStateSketch, ClientExecutor};
 
 #[derive(Clone)]
 pub struct MilestoneProofInputs {
     pub tx_data: String,
     pub tx_hash: FixedBytes<32>,
+    /// The expected CometBFT chain-id precommits must be signed for, e.g. `heimdall-137`.
+    pub chain_id: String,
     pub precommits: Vec<Vec<u8>>,
     pub sigs: Vec<String>,
     pub signers: Vec<Address>,
@@ -48,7 +32,7 @@ impl MilestoneProver {
         MilestoneProver { inputs }
     }
 
-    pub fn prove(&self) {
+    pub fn prove(&self) -> PublicValuesStruct {
         // Verify if the transaction data provided is actually correct or not
         let milestone = verify_tx_data(&self.inputs.tx_data, &self.inputs.tx_hash);
 
@@ -74,61 +58,33 @@ impl MilestoneProver {
         // This step also validates all of the storage against the provided state root.
         let executor = ClientExecutor::new(state_sketch).unwrap();
 
-        // Execute the `getEncodedValidatorInfo` call using the client executor to fetch the
-        // active validator's info from L1.
-        let call = ConsensusProofVerifier::getEncodedValidatorInfoCall {};
-        let input = ContractInput {
-            contract_address: VERIFIER_CONTRACT,
-            caller_address: CALLER,
-            calldata: call.clone(),
-        };
-        let output = executor.execute(input).unwrap();
-        let response = ConsensusProofVerifier::getEncodedValidatorInfoCall::abi_decode_returns(
-            &output.contractOutput,
-            true,
-        )
-        .unwrap();
-
-        // Extract the signers, powers, and total_power from the response.
-        let signers = response._0;
-        let powers = response._1;
-        let total_power = response._2;
-
-        let majority_power: Uint<256, 4> = Uint::from(0);
+        // Fetch the active validator's info from L1.
+        let (signers, powers, total_power) = common::fetch_validator_info(&executor);
         let mut validator_stakes = HashMap::new();
         for (i, signer) in signers.iter().enumerate() {
-            validator_stakes.insert(signer, powers[i]);
+            validator_stakes.insert(*signer, powers[i]);
         }
 
-        // Verify that the signatures generated by signing the precommit message are indeed signed
-        // by the given validators.
-        for i in 0..self.inputs.precommits.len() {
-            // Validate if the signer of this precommit message is a part of the active validator
-            // set or not.
-            assert!(validator_stakes.contains_key(&self.inputs.signers[i]));
-
-            // Verify if the precommit message is for the same milestone transaction or not.
-            let precommit = &self.inputs.precommits[i];
-            verify_precommit(&mut precommit.clone(), &self.inputs.tx_hash);
-
-            // Verify if the message is indeed signed by the validator or not.
-            verify_signature(
-                self.inputs.sigs[i].as_str(),
-                &keccak256(precommit),
-                self.inputs.signers[i],
-            );
-
-            // Add the power of the validator to the majority power
-            let _ = majority_power.add_mod(validator_stakes[&self.inputs.signers[i]], Uint::MAX);
-        }
+        // Verify the precommits, rejecting equivocation and disagreement on height/round, and
+        // tally the voting power behind them.
+        let majority_power = common::verify_quorum(
+            &self.inputs.precommits,
+            &self.inputs.sigs,
+            &self.inputs.signers,
+            &validator_stakes,
+            &self.inputs.tx_hash,
+            &self.inputs.chain_id,
+        );
 
-        // Check if the majority power is greater than 2/3rd of the total power
-        let expected_majority = total_power
-            .mul_mod(Uint::from(2), Uint::MAX)
-            .div_ceil(Uint::from(3));
-        if majority_power <= expected_majority {
+        // Check if the majority power meets the 2/3rd+ quorum threshold.
+        if !majority_power.has_quorum(total_power) {
             panic!("Majority voting power is less than 2/3rd of the total power");
         }
+
+        PublicValuesStruct {
+            bor_block_hash: self.inputs.bor_block_hash,
+            l1_block_hash: self.inputs.l1_block_hash,
+        }
     }
 
     pub fn get_data_from_l1(&self) {}